@@ -1,16 +1,26 @@
 use lambda_runtime::{service_fn, LambdaEvent, Error};
 use serde_json::Value;
+use tracing::info;
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt()
+        .json()
+        .with_max_level(tracing::Level::INFO)
+        // disable printing the name of the module in every log line.
+        .with_target(false)
+        // disabling time is handy because CloudWatch will add the ingestion time.
+        .without_time()
+        .init();
+
     let handler = service_fn(func);
     lambda_runtime::run(handler).await?;
     Ok(())
 }
 
 async fn func(event: LambdaEvent<Value>) -> Result<(), Error> {
-    let (payload, _context) = event.into_parts();
-    println!("Hello, World!");
-    println!("Received event: {}", payload);
+    let (payload, context) = event.into_parts();
+    info!(request_id = %context.request_id, "Hello, World!");
+    info!(request_id = %context.request_id, event = %payload, "Received event");
     Ok(())
 }